@@ -1,5 +1,5 @@
 use bevy_asset::Handle;
-use bevy_camera::visibility::Visibility;
+use bevy_camera::visibility::{RenderLayers, Visibility};
 use bevy_ecs::prelude::*;
 use bevy_image::Image;
 use bevy_math::Quat;
@@ -140,6 +140,207 @@ impl Default for GeneratedEnvironmentMapLight {
     }
 }
 
+/// A single step in a [`CubemapProgram`], describing an operation that should be applied
+/// in order to bake a source cubemap into the radiance consumed by an
+/// [`EnvironmentMapLight`].
+///
+/// This is reserved for future use: no system interprets a [`CubemapProgram`] yet, so
+/// these variants currently only describe the intended operation.
+#[derive(Clone, Debug, Reflect)]
+#[reflect(Debug, Clone)]
+pub enum CubemapOp {
+    /// Rotates the cubemap by the given world-space rotation, matching the rotation
+    /// convention already used by [`EnvironmentMapLight::rotation`].
+    Rotate(Quat),
+
+    /// Scales every texel of the cubemap by a constant factor.
+    ScaleIntensity(f32),
+
+    /// Cosine-weighted convolves the cubemap into diffuse irradiance, suitable for
+    /// producing an [`EnvironmentMapLight::diffuse_map`].
+    ConvolveDiffuse,
+
+    /// Prefilters the cubemap into a roughness-mipmapped specular radiance map,
+    /// suitable for producing an [`EnvironmentMapLight::specular_map`].
+    PrefilterSpecular {
+        /// The number of roughness levels to bake into the mip chain of the result.
+        roughness_levels: u32,
+    },
+}
+
+/// An ordered sequence of [`CubemapOp`]s describing how a source cubemap should be
+/// baked into the radiance map consumed by an [`EnvironmentMapLight`].
+///
+/// No executor for this program exists yet; see the note on [`CubemapOp`].
+#[derive(Clone, Debug, Default, Reflect)]
+#[reflect(Default, Debug, Clone)]
+pub struct CubemapProgram(pub Vec<CubemapOp>);
+
+impl CubemapProgram {
+    /// Creates an empty program, equivalent to using the source cubemap unmodified.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an operation to the end of the program.
+    #[inline]
+    pub fn with(mut self, op: CubemapOp) -> Self {
+        self.0.push(op);
+        self
+    }
+}
+
+/// Declares a composable bake pipeline intended to resolve into an
+/// [`EnvironmentMapLight`], allowing diffuse and specular radiance to be sourced from
+/// entirely independent cubemaps and filtered through independent [`CubemapProgram`]s.
+///
+/// This lets applications that bake diffuse irradiance offline, but want specular
+/// prefiltering done at runtime (or vice versa), describe the whole pipeline
+/// declaratively instead of authoring both maps by hand. Once run, the result would be
+/// written into this entity's [`EnvironmentMapLight`], which remains the resolved output
+/// consumed by the renderer — but no system runs a [`CubemapProgram`] yet, so adding this
+/// component has no effect today. Reserved for future use.
+///
+/// Like [`GeneratedEnvironmentMapLight`], this component does not `#[require]` an
+/// [`EnvironmentMapLight`], so adding it does not insert one with default, unbaked
+/// values.
+#[derive(Clone, Component, Reflect)]
+#[reflect(Component, Default, Clone)]
+pub struct EnvironmentMapLightPipeline {
+    /// Source cubemap from which [`diffuse_program`](Self::diffuse_program) bakes
+    /// [`EnvironmentMapLight::diffuse_map`].
+    pub diffuse_source: Handle<Image>,
+
+    /// The operations used to bake [`diffuse_source`](Self::diffuse_source) into
+    /// [`EnvironmentMapLight::diffuse_map`].
+    pub diffuse_program: CubemapProgram,
+
+    /// Source cubemap from which [`specular_program`](Self::specular_program) bakes
+    /// [`EnvironmentMapLight::specular_map`].
+    pub specular_source: Handle<Image>,
+
+    /// The operations used to bake [`specular_source`](Self::specular_source) into
+    /// [`EnvironmentMapLight::specular_map`].
+    pub specular_program: CubemapProgram,
+}
+
+impl Default for EnvironmentMapLightPipeline {
+    fn default() -> Self {
+        EnvironmentMapLightPipeline {
+            diffuse_source: Handle::default(),
+            diffuse_program: CubemapProgram::new().with(CubemapOp::ConvolveDiffuse),
+            specular_source: Handle::default(),
+            specular_program: CubemapProgram::new().with(CubemapOp::PrefilterSpecular {
+                roughness_levels: 5,
+            }),
+        }
+    }
+}
+
+/// Determines how often a [`ReflectionProbe`] re-captures the scene surrounding it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq, Clone)]
+pub enum ReflectionProbeUpdatePolicy {
+    /// Capture the scene a single time and never again, suitable for probes that only
+    /// need to see static geometry.
+    Once,
+    /// Recapture the scene every frame, giving the most accurate reflections of dynamic
+    /// geometry at the highest rendering cost.
+    #[default]
+    EveryFrame,
+    /// Recapture the scene every `n` frames, trading reflection accuracy for the cost of
+    /// a full cubemap capture and filter.
+    EveryNFrames(u32),
+}
+
+/// Declares the parameters for a probe that is meant to capture the surrounding scene
+/// into a cubemap at its world position and filter the result into diffuse and specular
+/// radiance, rather than consuming a pre-authored or pre-generated source image.
+///
+/// This component is reserved for future use: it only carries the desired capture
+/// parameters ([`capture_resolution`](Self::capture_resolution), [`near`](Self::near),
+/// [`far`](Self::far), [`layers`](Self::layers), and [`update_policy`](Self::update_policy)).
+/// Adding it to an entity has no effect today, since the system that would render the
+/// capture, run it through the same GPU filtering path used by
+/// [`GeneratedEnvironmentMapLight`], and slot the result into the light probe ranking
+/// documented on [`LightProbe`] has not landed yet.
+///
+/// Like [`EnvironmentMapLightPipeline`], this component does not `#[require]` an
+/// [`EnvironmentMapLight`]: it is a bake input, and the eventual capture/bake system
+/// would insert the [`EnvironmentMapLight`] once it completes, rather than relying on
+/// one being present (with default, un-captured values) up front.
+///
+/// This component requires the [`LightProbe`] component, and is typically used with
+/// [`bevy_transform::components::Transform`] to place the probe appropriately.
+#[derive(Clone, Component, Debug, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+#[require(LightProbe)]
+pub struct ReflectionProbe {
+    /// The resolution, in pixels, of each face of the captured cubemap.
+    ///
+    /// By default, this is 256.
+    pub capture_resolution: u32,
+
+    /// The distance to the near clipping plane used when rendering the capture.
+    ///
+    /// By default, this is 0.1.
+    pub near: f32,
+
+    /// The distance to the far clipping plane used when rendering the capture.
+    ///
+    /// By default, this is 1000.0.
+    pub far: f32,
+
+    /// Which entities are rendered into the capture. Only entities on a layer
+    /// contained in this mask are visible to the probe.
+    ///
+    /// By default, this is [`RenderLayers::default`].
+    pub layers: RenderLayers,
+
+    /// How often the cubemap capture (and the filtering derived from it) is refreshed.
+    ///
+    /// By default, this is [`ReflectionProbeUpdatePolicy::EveryFrame`].
+    pub update_policy: ReflectionProbeUpdatePolicy,
+
+    /// World-space rotation applied to the captured cubemap before it is filtered,
+    /// matching the rotation convention already used by [`EnvironmentMapLight::rotation`].
+    /// This is useful for users who require a different axis, such as the Z-axis, to
+    /// serve as the vertical axis.
+    ///
+    /// By default, this is [`Quat::IDENTITY`].
+    pub rotation: Quat,
+
+    /// Scale factor applied to the diffuse and specular light generated by this component.
+    ///
+    /// After applying this multiplier, the resulting values should
+    /// be in units of [cd/m^2](https://en.wikipedia.org/wiki/Candela_per_square_metre).
+    ///
+    /// By default, this is 0.0.
+    pub intensity: f32,
+
+    /// Whether the light from this probe contributes diffuse lighting to meshes that
+    /// already have baked lightmaps.
+    ///
+    /// By default, this is set to true.
+    pub affects_lightmapped_mesh_diffuse: bool,
+}
+
+impl Default for ReflectionProbe {
+    fn default() -> Self {
+        ReflectionProbe {
+            capture_resolution: 256,
+            near: 0.1,
+            far: 1000.0,
+            layers: RenderLayers::default(),
+            update_policy: ReflectionProbeUpdatePolicy::default(),
+            rotation: Quat::IDENTITY,
+            intensity: 0.0,
+            affects_lightmapped_mesh_diffuse: true,
+        }
+    }
+}
+
 /// The component that defines an irradiance volume.
 ///
 /// See `bevy_pbr::irradiance_volume` for detailed information.
@@ -186,3 +387,106 @@ impl Default for IrradianceVolume {
         }
     }
 }
+
+/// A cubemap sampled around a point or spot light to modulate its emitted radiance
+/// per-direction, turning the light into a colored gobo that can be projected, such as
+/// a stained-glass window or a shaped projector beam.
+///
+/// This component is meant to be added alongside a `PointLight` or a `SpotLight`, whose
+/// shader would multiply its contribution by a sample of [`cubemap`](Self::cubemap) taken
+/// along the light-to-fragment direction, rotated by [`rotation`](Self::rotation). Those
+/// light types live outside this crate, so there is no `#[require]` tying them together;
+/// check the light type's documentation for compatibility before attaching this
+/// component.
+///
+/// No shader integration exists yet, so attaching this component — to a light or to
+/// anything else — has no visible effect today. Reserved for future use. This reuses the
+/// same cubemap loading and layout infrastructure that the light probe types in this
+/// module depend on.
+#[derive(Clone, Component, Reflect)]
+#[reflect(Component, Default, Clone)]
+pub struct CubicLight {
+    /// The cubemap sampled along the light-to-fragment direction to modulate the
+    /// light's emitted radiance.
+    ///
+    /// By default, this is [`Handle::default`].
+    pub cubemap: Handle<Image>,
+
+    /// Scale factor applied to the cubemap-modulated light.
+    ///
+    /// By default, this is 1.0.
+    pub intensity: f32,
+
+    /// World-space rotation applied to the cubemap, matching the rotation convention
+    /// already used by [`EnvironmentMapLight::rotation`].
+    ///
+    /// By default, this is [`Quat::IDENTITY`].
+    pub rotation: Quat,
+}
+
+impl Default for CubicLight {
+    fn default() -> Self {
+        CubicLight {
+            cubemap: Handle::default(),
+            intensity: 1.0,
+            rotation: Quat::IDENTITY,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reflection_probe_default_matches_documented_values() {
+        let probe = ReflectionProbe::default();
+        assert_eq!(probe.capture_resolution, 256);
+        assert_eq!(probe.near, 0.1);
+        assert_eq!(probe.far, 1000.0);
+        assert_eq!(probe.layers, RenderLayers::default());
+        assert_eq!(probe.update_policy, ReflectionProbeUpdatePolicy::EveryFrame);
+        assert_eq!(probe.rotation, Quat::IDENTITY);
+        assert_eq!(probe.intensity, 0.0);
+        assert!(probe.affects_lightmapped_mesh_diffuse);
+    }
+
+    #[test]
+    fn reflection_probe_update_policy_default_is_every_frame() {
+        assert_eq!(
+            ReflectionProbeUpdatePolicy::default(),
+            ReflectionProbeUpdatePolicy::EveryFrame
+        );
+    }
+
+    #[test]
+    fn cubemap_program_with_appends_in_order() {
+        let program = CubemapProgram::new()
+            .with(CubemapOp::Rotate(Quat::IDENTITY))
+            .with(CubemapOp::ScaleIntensity(2.0));
+        assert_eq!(program.0.len(), 2);
+        assert!(matches!(program.0[0], CubemapOp::Rotate(_)));
+        assert!(matches!(program.0[1], CubemapOp::ScaleIntensity(_)));
+    }
+
+    #[test]
+    fn environment_map_light_pipeline_default_bakes_diffuse_and_specular() {
+        let pipeline = EnvironmentMapLightPipeline::default();
+        assert!(matches!(
+            pipeline.diffuse_program.0.as_slice(),
+            [CubemapOp::ConvolveDiffuse]
+        ));
+        assert!(matches!(
+            pipeline.specular_program.0.as_slice(),
+            [CubemapOp::PrefilterSpecular { roughness_levels: 5 }]
+        ));
+    }
+
+    #[test]
+    fn cubic_light_default_matches_documented_values() {
+        let light = CubicLight::default();
+        assert_eq!(light.cubemap, Handle::default());
+        assert_eq!(light.intensity, 1.0);
+        assert_eq!(light.rotation, Quat::IDENTITY);
+    }
+}